@@ -0,0 +1,82 @@
+/// Solves a system of congruences `x ≡ residues[i] (mod moduli[i])` using
+/// incremental Garner merging. Returns the smallest non-negative solution
+/// together with the combined modulus (the LCM of `moduli`), or [`None`] when
+/// the constraints are inconsistent.
+pub fn crt(residues: &[i64], moduli: &[i64]) -> Option<(i64, i64)> {
+    let (mut r, mut m) = (0i128, 1i128);
+    for (&a, &n) in residues.iter().zip(moduli.iter()) {
+        let (a, n) = (a as i128, n as i128);
+        let g = gcd(m, n);
+        if (a - r) % g != 0 {
+            return None;
+        }
+        let lcm = m / g * n;
+        // Solve `r + m * k ≡ a (mod n)` for `k`, working modulo `n / g`.
+        let md = n / g;
+        let inv = mod_inv(m / g, md)?;
+        let k = (a - r) / g % md * inv % md;
+        r = (r + m * k).rem_euclid(lcm);
+        m = lcm;
+    }
+    Some((r as i64, m as i64))
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+/// Returns the modular inverse of `a` modulo `m` via the extended Euclidean
+/// algorithm, or [`None`] when `a` and `m` are not coprime.
+fn mod_inv(a: i128, m: i128) -> Option<i128> {
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    if old_r != 1 {
+        None
+    } else {
+        Some(old_s.rem_euclid(m))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_crt_single() {
+        assert_eq!(crt(&[2], &[3]), Some((2, 3)));
+    }
+
+    #[test]
+    fn test_crt_coprime() {
+        // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7) => 23 (mod 105).
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+    }
+
+    #[test]
+    fn test_crt_non_coprime() {
+        // x ≡ 1 (mod 4), x ≡ 3 (mod 6) => 9 (mod 12).
+        assert_eq!(crt(&[1, 3], &[4, 6]), Some((9, 12)));
+    }
+
+    #[test]
+    fn test_crt_inconsistent() {
+        assert_eq!(crt(&[1, 2], &[4, 6]), None);
+    }
+
+    #[test]
+    fn test_crt_bus_schedule() {
+        // t ≡ -i (mod m_i) cyclic bus scheduling example => 1068781.
+        let moduli = [7i64, 13, 59, 31, 19];
+        let residues: Vec<i64> = [0i64, 1, 4, 6, 7]
+            .iter()
+            .map(|&i| -i)
+            .collect();
+        assert_eq!(crt(&residues, &moduli), Some((1068781, 3162341)));
+    }
+}
@@ -1,5 +1,8 @@
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::hash::Hash;
 use std::ops::{Add, Mul, Neg, Sub};
+use num::{One, ToPrimitive};
 use num::traits::{WrappingAdd, WrappingMul, WrappingNeg, WrappingSub};
 
 /// Represents a position in a 2D space.
@@ -95,6 +98,34 @@ impl<T: Copy + WrappingAdd + WrappingSub> Pos<T> {
     }
 }
 
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Pos<T> {
+    /// Returns the Manhattan (taxicab) distance `|dx| + |dy|` to `other`.
+    #[inline]
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Returns the Chebyshev (chessboard) distance `max(|dx|, |dy|)` to `other`.
+    #[inline]
+    pub fn chebyshev(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Returns the squared Euclidean distance `dx * dx + dy * dy` to `other`.
+    #[inline]
+    pub fn euclidean_sq(&self, other: &Self) -> T {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        dx * dx + dy * dy
+    }
+}
+
+/// Returns the absolute difference between `a` and `b` without underflowing on
+/// unsigned types.
+#[inline]
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
 impl<T: Copy + WrappingNeg> Pos<T> {
     /// Returns the current [`Pos<T>`] turned 90 degrees clockwise.
     #[inline]
@@ -109,6 +140,265 @@ impl<T: Copy + WrappingNeg> Pos<T> {
     }
 }
 
+impl<T: Copy + PartialOrd + One + ToPrimitive + Add<Output = T>> Pos<T> {
+    /// Yields every position in the inclusive rectangle `min..=max` in
+    /// row-major order (all `x` for a row of `y`, then the next `y`).
+    #[inline]
+    pub fn range(min: Self, max: Self) -> impl Iterator<Item = Self> {
+        num::iter::range_inclusive(min.y, max.y).flat_map(move |y| {
+            num::iter::range_inclusive(min.x, max.x).map(move |x| Self { x, y })
+        })
+    }
+}
+
+/// An inclusive rectangular region of positions between two corners.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Region<T> {
+    pub min: Pos<T>,
+    pub max: Pos<T>,
+}
+
+impl<T> Region<T> {
+    /// Creates a region spanning the inclusive rectangle `min..=max`.
+    #[inline]
+    pub fn new(min: Pos<T>, max: Pos<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: Copy + PartialOrd> Region<T> {
+    /// Returns whether `p` lies within the inclusive bounds of this region.
+    #[inline]
+    pub fn contains(&self, p: &Pos<T>) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+}
+
+impl<T: Copy + PartialOrd + One + ToPrimitive + Add<Output = T>> Region<T> {
+    /// Yields every position in the region in row-major order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Pos<T>> {
+        Pos::range(self.min, self.max)
+    }
+}
+
+/// Represents a position in a 3D space.
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Pos3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Default> Default for Pos3<T> {
+    #[inline]
+    fn default() -> Self {
+        Self { x: T::default(), y: T::default(), z: T::default() }
+    }
+}
+
+impl<T: Display> Display for Pos3<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: WrappingAdd<Output = T>> Add for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x.wrapping_add(&rhs.x),
+            y: self.y.wrapping_add(&rhs.y),
+            z: self.z.wrapping_add(&rhs.z),
+        }
+    }
+}
+
+impl<T: WrappingSub<Output = T>> Sub for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x.wrapping_sub(&rhs.x),
+            y: self.y.wrapping_sub(&rhs.y),
+            z: self.z.wrapping_sub(&rhs.z),
+        }
+    }
+}
+
+impl<T: WrappingMul<Output = T>> Mul<T> for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x.wrapping_mul(&rhs),
+            y: self.y.wrapping_mul(&rhs),
+            z: self.z.wrapping_mul(&rhs),
+        }
+    }
+}
+
+impl<T: WrappingNeg> Neg for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { x: self.x.wrapping_neg(), y: self.y.wrapping_neg(), z: self.z.wrapping_neg() }
+    }
+}
+
+impl<T: Copy + WrappingAdd + WrappingSub> Pos3<T> {
+    /// Returns the 26 neighbours surrounding this position, excluding itself.
+    #[inline]
+    pub fn neighbours(&self, dist: T) -> Vec<Self> {
+        let xs = [self.x.wrapping_sub(&dist), self.x, self.x.wrapping_add(&dist)];
+        let ys = [self.y.wrapping_sub(&dist), self.y, self.y.wrapping_add(&dist)];
+        let zs = [self.z.wrapping_sub(&dist), self.z, self.z.wrapping_add(&dist)];
+        let mut out = Vec::with_capacity(26);
+        for (i, &x) in xs.iter().enumerate() {
+            for (j, &y) in ys.iter().enumerate() {
+                for (k, &z) in zs.iter().enumerate() {
+                    if i == 1 && j == 1 && k == 1 {
+                        continue;
+                    }
+                    out.push(Self { x, y, z });
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Represents a position in a 4D space.
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Pos4<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Default> Default for Pos4<T> {
+    #[inline]
+    fn default() -> Self {
+        Self { x: T::default(), y: T::default(), z: T::default(), w: T::default() }
+    }
+}
+
+impl<T: Display> Display for Pos4<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl<T: WrappingAdd<Output = T>> Add for Pos4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x.wrapping_add(&rhs.x),
+            y: self.y.wrapping_add(&rhs.y),
+            z: self.z.wrapping_add(&rhs.z),
+            w: self.w.wrapping_add(&rhs.w),
+        }
+    }
+}
+
+impl<T: WrappingSub<Output = T>> Sub for Pos4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x.wrapping_sub(&rhs.x),
+            y: self.y.wrapping_sub(&rhs.y),
+            z: self.z.wrapping_sub(&rhs.z),
+            w: self.w.wrapping_sub(&rhs.w),
+        }
+    }
+}
+
+impl<T: WrappingMul<Output = T>> Mul<T> for Pos4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x.wrapping_mul(&rhs),
+            y: self.y.wrapping_mul(&rhs),
+            z: self.z.wrapping_mul(&rhs),
+            w: self.w.wrapping_mul(&rhs),
+        }
+    }
+}
+
+impl<T: WrappingNeg> Neg for Pos4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            x: self.x.wrapping_neg(),
+            y: self.y.wrapping_neg(),
+            z: self.z.wrapping_neg(),
+            w: self.w.wrapping_neg(),
+        }
+    }
+}
+
+impl<T: Copy + WrappingAdd + WrappingSub> Pos4<T> {
+    /// Returns the 80 neighbours surrounding this position, excluding itself.
+    #[inline]
+    pub fn neighbours(&self, dist: T) -> Vec<Self> {
+        let xs = [self.x.wrapping_sub(&dist), self.x, self.x.wrapping_add(&dist)];
+        let ys = [self.y.wrapping_sub(&dist), self.y, self.y.wrapping_add(&dist)];
+        let zs = [self.z.wrapping_sub(&dist), self.z, self.z.wrapping_add(&dist)];
+        let ws = [self.w.wrapping_sub(&dist), self.w, self.w.wrapping_add(&dist)];
+        let mut out = Vec::with_capacity(80);
+        for (i, &x) in xs.iter().enumerate() {
+            for (j, &y) in ys.iter().enumerate() {
+                for (k, &z) in zs.iter().enumerate() {
+                    for (l, &w) in ws.iter().enumerate() {
+                        if i == 1 && j == 1 && k == 1 && l == 1 {
+                            continue;
+                        }
+                        out.push(Self { x, y, z, w });
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Advances one generation of the 3D Conway pocket dimension: a cell stays
+/// active with 2 or 3 active neighbours and an inactive cell becomes active
+/// with exactly 3, with the grid growing implicitly by one layer per step.
+pub fn conway_step<T>(active: &HashSet<Pos3<T>>) -> HashSet<Pos3<T>>
+where
+    T: Copy + Eq + Hash + One + WrappingAdd + WrappingSub,
+{
+    let one = T::one();
+    let mut counts: HashMap<Pos3<T>, usize> = HashMap::new();
+    for cell in active {
+        for n in cell.neighbours(one) {
+            *counts.entry(n).or_insert(0) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|&(pos, c)| c == 3 || (c == 2 && active.contains(&pos)))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -226,4 +516,179 @@ mod test {
         let sut = Pos { x: -1, y: 1 }.counterclockwise();
         assert_eq!(sut, Pos { x: -1, y: -1 });
     }
+
+    #[test]
+    fn test_manhattan() {
+        let sut = Pos { x: 1, y: 2 }.manhattan(&Pos { x: 4, y: -2 });
+        assert_eq!(sut, 7);
+
+        let sut = Pos { x: 1u32, y: 5u32 }.manhattan(&Pos { x: 4u32, y: 2u32 });
+        assert_eq!(sut, 6);
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        let sut = Pos { x: 1, y: 2 }.chebyshev(&Pos { x: 4, y: -2 });
+        assert_eq!(sut, 4);
+
+        let sut = Pos { x: 1u32, y: 5u32 }.chebyshev(&Pos { x: 4u32, y: 2u32 });
+        assert_eq!(sut, 3);
+    }
+
+    #[test]
+    fn test_euclidean_sq() {
+        let sut = Pos { x: 1, y: 2 }.euclidean_sq(&Pos { x: 4, y: 6 });
+        assert_eq!(sut, 25);
+
+        let sut = Pos { x: 1u32, y: 5u32 }.euclidean_sq(&Pos { x: 4u32, y: 1u32 });
+        assert_eq!(sut, 25);
+    }
+
+    #[test]
+    fn test_range() {
+        let sut: Vec<_> = Pos::range(Pos { x: 0, y: 0 }, Pos { x: 1, y: 1 }).collect();
+        assert_eq!(
+            sut,
+            vec![
+                Pos { x: 0, y: 0 },
+                Pos { x: 1, y: 0 },
+                Pos { x: 0, y: 1 },
+                Pos { x: 1, y: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range_single() {
+        let sut: Vec<_> = Pos::range(Pos { x: 2, y: 3 }, Pos { x: 2, y: 3 }).collect();
+        assert_eq!(sut, vec![Pos { x: 2, y: 3 }]);
+    }
+
+    #[test]
+    fn test_region_contains() {
+        let sut = Region::new(Pos { x: -1, y: -1 }, Pos { x: 1, y: 1 });
+        assert!(sut.contains(&Pos { x: 0, y: 0 }));
+        assert!(sut.contains(&Pos { x: -1, y: 1 }));
+        assert!(!sut.contains(&Pos { x: 2, y: 0 }));
+        assert!(!sut.contains(&Pos { x: 0, y: -2 }));
+    }
+
+    #[test]
+    fn test_region_iter() {
+        let sut: Vec<_> = Region::new(Pos { x: 0, y: 0 }, Pos { x: 1, y: 1 }).iter().collect();
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut[0], Pos { x: 0, y: 0 });
+        assert_eq!(sut[3], Pos { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn test_pos3_default() {
+        assert_eq!(Pos3::default(), Pos3 { x: 0, y: 0, z: 0 });
+    }
+
+    #[test]
+    fn test_pos3_display() {
+        let sut = Pos3 { x: 1, y: -2, z: 3 };
+        assert_eq!(format!("{}", sut), "(1, -2, 3)");
+    }
+
+    #[test]
+    fn test_pos3_add() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } + Pos3 { x: 3, y: 4, z: 5 };
+        assert_eq!(sut, Pos3 { x: 4, y: 6, z: 8 });
+    }
+
+    #[test]
+    fn test_pos3_sub() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } - Pos3 { x: 3, y: 4, z: 5 };
+        assert_eq!(sut, Pos3 { x: -2, y: -2, z: -2 });
+    }
+
+    #[test]
+    fn test_pos3_mul() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } * -2;
+        assert_eq!(sut, Pos3 { x: -2, y: -4, z: -6 });
+    }
+
+    #[test]
+    fn test_pos3_neg() {
+        let sut = -Pos3 { x: 1, y: 2, z: 3 };
+        assert_eq!(sut, Pos3 { x: -1, y: -2, z: -3 });
+    }
+
+    #[test]
+    fn test_pos3_neighbours() {
+        let sut = Pos3 { x: 0, y: 0, z: 0 }.neighbours(1);
+        assert_eq!(sut.len(), 26);
+        assert!(!sut.contains(&Pos3 { x: 0, y: 0, z: 0 }));
+        assert!(sut.contains(&Pos3 { x: 1, y: 1, z: 1 }));
+        assert!(sut.contains(&Pos3 { x: -1, y: 0, z: 1 }));
+    }
+
+    #[test]
+    fn test_pos4_default() {
+        assert_eq!(Pos4::default(), Pos4 { x: 0, y: 0, z: 0, w: 0 });
+    }
+
+    #[test]
+    fn test_pos4_display() {
+        let sut = Pos4 { x: 1, y: -2, z: 3, w: -4 };
+        assert_eq!(format!("{}", sut), "(1, -2, 3, -4)");
+    }
+
+    #[test]
+    fn test_pos4_add() {
+        let sut = Pos4 { x: 1, y: 2, z: 3, w: 4 } + Pos4 { x: 4, y: 3, z: 2, w: 1 };
+        assert_eq!(sut, Pos4 { x: 5, y: 5, z: 5, w: 5 });
+    }
+
+    #[test]
+    fn test_pos4_sub() {
+        let sut = Pos4 { x: 1, y: 2, z: 3, w: 4 } - Pos4 { x: 4, y: 3, z: 2, w: 1 };
+        assert_eq!(sut, Pos4 { x: -3, y: -1, z: 1, w: 3 });
+    }
+
+    #[test]
+    fn test_pos4_mul() {
+        let sut = Pos4 { x: 1, y: 2, z: 3, w: 4 } * 2;
+        assert_eq!(sut, Pos4 { x: 2, y: 4, z: 6, w: 8 });
+    }
+
+    #[test]
+    fn test_pos4_neg() {
+        let sut = -Pos4 { x: 1, y: 2, z: 3, w: 4 };
+        assert_eq!(sut, Pos4 { x: -1, y: -2, z: -3, w: -4 });
+    }
+
+    #[test]
+    fn test_pos4_neighbours() {
+        let sut = Pos4 { x: 0, y: 0, z: 0, w: 0 }.neighbours(1);
+        assert_eq!(sut.len(), 80);
+        assert!(!sut.contains(&Pos4 { x: 0, y: 0, z: 0, w: 0 }));
+        assert!(sut.contains(&Pos4 { x: 1, y: 1, z: 1, w: 1 }));
+        assert!(sut.contains(&Pos4 { x: -1, y: 0, z: 1, w: 0 }));
+    }
+
+    #[test]
+    fn test_conway_step() {
+        // A single active cell has no neighbour with 2 or 3 active cells,
+        // so the dimension empties out after one step.
+        let mut active = HashSet::new();
+        active.insert(Pos3 { x: 0, y: 0, z: 0 });
+        assert!(conway_step(&active).is_empty());
+
+        // A 3-cell line along x: the centre stays active (2 neighbours) and
+        // the cells next to the centre along y and z become active (3
+        // neighbours each).
+        let mut active = HashSet::new();
+        active.insert(Pos3 { x: -1, y: 0, z: 0 });
+        active.insert(Pos3 { x: 0, y: 0, z: 0 });
+        active.insert(Pos3 { x: 1, y: 0, z: 0 });
+        let next = conway_step(&active);
+        assert!(next.contains(&Pos3 { x: 0, y: 0, z: 0 }));
+        assert!(next.contains(&Pos3 { x: 0, y: 1, z: 0 }));
+        assert!(next.contains(&Pos3 { x: 0, y: -1, z: 0 }));
+        assert!(next.contains(&Pos3 { x: 0, y: 0, z: 1 }));
+        assert!(next.contains(&Pos3 { x: 0, y: 0, z: -1 }));
+    }
 }